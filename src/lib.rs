@@ -0,0 +1,6 @@
+pub mod orchestrator;
+pub mod registry;
+pub mod scheduler;
+pub mod sink;
+pub mod task;
+pub mod task_blueprint;
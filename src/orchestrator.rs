@@ -1,29 +1,109 @@
 use crate::{
-    task::{TaskInput, TaskResult, TaskStatus},
+    registry::TaskRegistry,
+    sink::{CsvResultSink, ResultSink},
+    task::{TaskInput, TaskOutput, TaskResult, TaskStatus},
     task_blueprint::TaskBlueprint,
 };
+use ahash::AHashMap as HashMap;
+use anyhow::Result;
 use futures::stream::{FuturesUnordered, StreamExt};
-use tokio::sync::mpsc;
+use rand::Rng;
+use std::sync::Arc;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::sync::{mpsc, Semaphore};
+use tokio::time::{sleep, Duration};
 
-pub struct TaskOrchestrator;
+/// Maximum number of attempts made for a single task before giving up.
+const MAX_ATTEMPTS: u32 = 5;
+/// Backoff delay before the first retry.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+/// Backoff never grows past this.
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+pub struct TaskOrchestrator {
+    max_concurrency: Option<usize>,
+    sink: Arc<dyn ResultSink>,
+}
 
 impl TaskOrchestrator {
     pub fn new() -> Self {
-        Self
+        Self {
+            max_concurrency: None,
+            sink: Arc::new(CsvResultSink::new()),
+        }
+    }
+
+    /// Caps the number of tasks executed in parallel. Without this, a large
+    /// CSV opens one `reqwest` connection per row at once, which can exhaust
+    /// file descriptors.
+    pub fn with_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = Some(max_concurrency);
+        self
+    }
+
+    /// Swaps the result persistence backend. Defaults to an in-memory
+    /// `CsvResultSink`; use this to plug in e.g. a `PostgresResultSink`.
+    pub fn with_sink(mut self, sink: Arc<dyn ResultSink>) -> Self {
+        self.sink = sink;
+        self
     }
 
     pub async fn execute_tasks(self, tasks: Vec<TaskInput>) -> Vec<TaskResult> {
-        let (tx, mut rx) = mpsc::channel::<TaskResult>(1000);
+        self.execute_tasks_tracked(tasks, TaskRegistry::new()).await
+    }
 
-        let mut handles = Vec::new();
+    /// Like [`TaskOrchestrator::execute_tasks`], but also returns a
+    /// [`TaskRegistry`] that is populated as tasks are spawned, so the
+    /// caller can query progress or cancel individual tasks while the run
+    /// (driven by the returned `JoinHandle`) is still in flight.
+    pub fn execute_tasks_with_registry(
+        self,
+        tasks: Vec<TaskInput>,
+    ) -> (TaskRegistry, tokio::task::JoinHandle<Vec<TaskResult>>) {
+        let registry = TaskRegistry::new();
+        let registry_clone = registry.clone();
+        let handle =
+            tokio::spawn(async move { self.execute_tasks_tracked(tasks, registry_clone).await });
+        (registry, handle)
+    }
+
+    async fn execute_tasks_tracked(
+        self,
+        tasks: Vec<TaskInput>,
+        registry: TaskRegistry,
+    ) -> Vec<TaskResult> {
+        let semaphore = self.max_concurrency.map(|n| Arc::new(Semaphore::new(n)));
+        let (tx, mut rx) = mpsc::channel::<TaskResult>(1000);
 
         for task in tasks {
             let tx_clone = tx.clone();
+            let semaphore = semaphore.clone();
+            let sink = self.sink.clone();
+            let task_registry = registry.clone();
+            let task_id = task.task_id;
+
+            // Register the task as `Running` *before* spawning it, so a
+            // `set_status` that races ahead of the `attach_handle` call
+            // below still lands on a real entry instead of being dropped.
+            registry.insert_pending(task_id).await;
+
             let handle = tokio::spawn(async move {
-                let result = Self::execute_single_task(task.task_id).await;
+                let _permit = match &semaphore {
+                    Some(sem) => Some(sem.clone().acquire_owned().await.expect("semaphore closed")),
+                    None => None,
+                };
+                let result = Self::execute_single_task(task_id).await;
+                drop(_permit);
+                task_registry
+                    .set_status(task_id, result.status.clone(), result.error_info.clone())
+                    .await;
+                if let Err(e) = sink.record(&result).await {
+                    eprintln!("failed to record result for task {}: {e}", result.task_id);
+                }
                 let _ = tx_clone.send(result).await;
             });
-            handles.push(handle);
+
+            registry.attach_handle(task_id, handle).await;
         }
 
         // Drop the original sender to signal completion
@@ -34,45 +114,391 @@ impl TaskOrchestrator {
             results.push(result);
         }
 
-        for handle in handles {
-            let _ = handle.await;
-        }
-
         results
     }
 
-    /// Streaming variant for handling large numbers of tasks efficiently (backpressure)
+    /// Streaming variant for handling large numbers of tasks efficiently
+    /// (backpressure). At most `max_concurrency` tasks are in flight at
+    /// once; the next task is only pulled in as one of the in-flight ones
+    /// completes.
     pub async fn execute_tasks_streaming(self, tasks: Vec<TaskInput>) -> Vec<TaskResult> {
+        let max_in_flight = self.max_concurrency.unwrap_or(usize::MAX);
+        let sink = self.sink.clone();
+        let mut pending = tasks.into_iter();
         let mut futures = FuturesUnordered::new();
 
-        for task in tasks {
-            futures.push(Self::execute_single_task(task.task_id));
+        let run_and_record = |task_id: u64, sink: Arc<dyn ResultSink>| async move {
+            let result = Self::execute_single_task(task_id).await;
+            if let Err(e) = sink.record(&result).await {
+                eprintln!("failed to record result for task {}: {e}", result.task_id);
+            }
+            result
+        };
+
+        for task in pending.by_ref().take(max_in_flight) {
+            futures.push(run_and_record(task.task_id, sink.clone()));
         }
 
         let mut results = Vec::new();
         while let Some(result) = futures.next().await {
             results.push(result);
+
+            if let Some(task) = pending.next() {
+                futures.push(run_and_record(task.task_id, sink.clone()));
+            }
         }
 
         results
     }
 
-    async fn execute_single_task(task_id: u64) -> TaskResult {
-        let mut result = TaskResult {
+    /// Streams each `TaskResult` to `writer` as a CSV row as soon as the
+    /// task completes, instead of buffering every result in memory like
+    /// [`TaskOrchestrator::execute_tasks`] does. The header row is written
+    /// once, up front.
+    ///
+    /// De-duplication of repeated `task_id`s can't use the "collect then
+    /// overwrite" trick `write_results_to_csv` uses, since rows are flushed
+    /// as they arrive. Instead, ids that appear more than once in `tasks`
+    /// are counted up front; every completion for such an id is suppressed
+    /// until its count reaches zero, at which point the *last* completion
+    /// to arrive — the one currently in hand — is the one written. Ids
+    /// that appear once — the common case — are written straight through
+    /// with no suppression at all.
+    pub async fn execute_tasks_to_writer<W: AsyncWrite + Unpin>(
+        self,
+        tasks: Vec<TaskInput>,
+        mut writer: W,
+    ) -> Result<()> {
+        let mut remaining: HashMap<u64, u32> = HashMap::default();
+        for task in &tasks {
+            *remaining.entry(task.task_id).or_insert(0) += 1;
+        }
+
+        let semaphore = self.max_concurrency.map(|n| Arc::new(Semaphore::new(n)));
+        let (tx, rx) = mpsc::channel::<TaskResult>(1000);
+        let mut handles = Vec::new();
+
+        for task in tasks {
+            let tx_clone = tx.clone();
+            let semaphore = semaphore.clone();
+            let sink = self.sink.clone();
+            let handle = tokio::spawn(async move {
+                let _permit = match &semaphore {
+                    Some(sem) => Some(sem.clone().acquire_owned().await.expect("semaphore closed")),
+                    None => None,
+                };
+                let result = Self::execute_single_task(task.task_id).await;
+                drop(_permit);
+                if let Err(e) = sink.record(&result).await {
+                    eprintln!("failed to record result for task {}: {e}", result.task_id);
+                }
+                let _ = tx_clone.send(result).await;
+            });
+            handles.push(handle);
+        }
+        drop(tx);
+
+        Self::dedupe_and_write(rx, remaining, &mut writer).await?;
+
+        for handle in handles {
+            let _ = handle.await;
+        }
+
+        writer.flush().await?;
+        Ok(())
+    }
+
+    /// Drains `rx`, writing each result's row once its id's `remaining`
+    /// count reaches zero. Earlier completions for a duplicated id are
+    /// dropped outright; whichever completion is in hand when the count
+    /// hits zero is necessarily the last one to arrive, so it's the row
+    /// that gets written.
+    async fn dedupe_and_write<W: AsyncWrite + Unpin>(
+        mut rx: mpsc::Receiver<TaskResult>,
+        mut remaining: HashMap<u64, u32>,
+        writer: &mut W,
+    ) -> Result<()> {
+        // Header goes out before any row, even if no result ever arrives.
+        let mut header = csv::Writer::from_writer(vec![]);
+        header.write_record(["task_id", "final_status", "error_info", "attempts"])?;
+        writer.write_all(&header.into_inner()?).await?;
+        let mut header_written = true;
+
+        while let Some(result) = rx.recv().await {
+            let count = remaining.get_mut(&result.task_id).expect("unknown task_id");
+            *count -= 1;
+
+            if *count == 0 {
+                Self::write_row(writer, result, &mut header_written).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn write_row<W: AsyncWrite + Unpin>(
+        writer: &mut W,
+        result: TaskResult,
+        header_written: &mut bool,
+    ) -> Result<()> {
+        let mut csv_writer = csv::WriterBuilder::new()
+            .has_headers(false)
+            .from_writer(vec![]);
+
+        if !*header_written {
+            csv_writer.write_record(["task_id", "final_status", "error_info", "attempts"])?;
+            *header_written = true;
+        }
+
+        let output: TaskOutput = result.into();
+        csv_writer.serialize(output)?;
+
+        writer.write_all(&csv_writer.into_inner()?).await?;
+        Ok(())
+    }
+
+    /// Runs `tasks` respecting `TaskInput::depends_on`: a task is only
+    /// dispatched once every dependency present in `tasks` has completed
+    /// successfully. If a dependency ends in `Failed`, every task that
+    /// transitively depends on it is marked `Failed` with an `error_info` of
+    /// `"skipped: upstream <id> failed"` instead of being run. Dependency
+    /// ids that aren't present in `tasks` are treated as already satisfied.
+    ///
+    /// Returns an error up front, before running anything, if the
+    /// dependency graph contains a cycle.
+    pub async fn execute_tasks_with_dependencies(
+        self,
+        tasks: Vec<TaskInput>,
+    ) -> Result<Vec<TaskResult>> {
+        let known_ids: HashMap<u64, ()> = tasks.iter().map(|t| (t.task_id, ())).collect();
+        // One execution per unique id (`in_degree` below is keyed by
+        // `task_id`, so a duplicate doesn't get a second dispatch); a
+        // duplicated id must not be double-counted here or the completion
+        // loop would wait on a result that never arrives.
+        let total = known_ids.len();
+
+        let mut in_degree: HashMap<u64, usize> = HashMap::default();
+        let mut dependents: HashMap<u64, Vec<u64>> = HashMap::default();
+
+        for task in &tasks {
+            let known_deps: Vec<u64> = task
+                .depends_on
+                .iter()
+                .copied()
+                .filter(|id| known_ids.contains_key(id))
+                .collect();
+            in_degree.insert(task.task_id, known_deps.len());
+            for dep in known_deps {
+                dependents.entry(dep).or_default().push(task.task_id);
+            }
+        }
+
+        Self::reject_cycles(&in_degree, &dependents)?;
+
+        let semaphore = self.max_concurrency.map(|n| Arc::new(Semaphore::new(n)));
+        let sink = self.sink.clone();
+        let (tx, mut rx) = mpsc::channel::<TaskResult>(1000);
+
+        let mut remaining_in_degree = in_degree;
+        let mut results: HashMap<u64, TaskResult> = HashMap::default();
+
+        let initially_ready: Vec<u64> = remaining_in_degree
+            .iter()
+            .filter(|(_, deg)| **deg == 0)
+            .map(|(id, _)| *id)
+            .collect();
+        for id in &initially_ready {
+            remaining_in_degree.remove(id);
+        }
+        Self::spawn_ready(initially_ready, &semaphore, &sink, &tx);
+
+        while results.len() < total {
+            let result = rx
+                .recv()
+                .await
+                .expect("a task is ready but no result arrived");
+            let task_id = result.task_id;
+            let failed = result.status == TaskStatus::Failed;
+            results.insert(task_id, result);
+
+            let Some(deps) = dependents.get(&task_id).cloned() else {
+                continue;
+            };
+
+            if failed {
+                let mut skipped = Vec::new();
+                for dependent in deps {
+                    Self::fail_transitively(
+                        dependent,
+                        task_id,
+                        &dependents,
+                        &mut results,
+                        &mut remaining_in_degree,
+                        &mut skipped,
+                    );
+                }
+                for result in &skipped {
+                    if let Err(e) = sink.record(result).await {
+                        eprintln!("failed to record result for task {}: {e}", result.task_id);
+                    }
+                }
+                continue;
+            }
+
+            let mut newly_ready = Vec::new();
+            for dependent in deps {
+                if let Some(deg) = remaining_in_degree.get_mut(&dependent) {
+                    *deg -= 1;
+                    if *deg == 0 {
+                        remaining_in_degree.remove(&dependent);
+                        newly_ready.push(dependent);
+                    }
+                }
+            }
+            Self::spawn_ready(newly_ready, &semaphore, &sink, &tx);
+        }
+
+        Ok(tasks
+            .iter()
+            .filter_map(|task| results.get(&task.task_id).cloned())
+            .collect())
+    }
+
+    fn spawn_ready(
+        ready: Vec<u64>,
+        semaphore: &Option<Arc<Semaphore>>,
+        sink: &Arc<dyn ResultSink>,
+        tx: &mpsc::Sender<TaskResult>,
+    ) {
+        for task_id in ready {
+            let semaphore = semaphore.clone();
+            let sink = sink.clone();
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let _permit = match &semaphore {
+                    Some(sem) => Some(sem.clone().acquire_owned().await.expect("semaphore closed")),
+                    None => None,
+                };
+                let result = Self::execute_single_task(task_id).await;
+                drop(_permit);
+                if let Err(e) = sink.record(&result).await {
+                    eprintln!("failed to record result for task {}: {e}", result.task_id);
+                }
+                let _ = tx.send(result).await;
+            });
+        }
+    }
+
+    /// Marks `task_id` (and everything that transitively depends on it) as
+    /// `Failed` without running it, because `upstream_id` failed. Every
+    /// newly-skipped result is appended to `skipped` so the caller can run
+    /// it through the sink, the same as an executed task's result is.
+    fn fail_transitively(
+        task_id: u64,
+        upstream_id: u64,
+        dependents: &HashMap<u64, Vec<u64>>,
+        results: &mut HashMap<u64, TaskResult>,
+        remaining_in_degree: &mut HashMap<u64, usize>,
+        skipped: &mut Vec<TaskResult>,
+    ) {
+        if results.contains_key(&task_id) {
+            return;
+        }
+
+        remaining_in_degree.remove(&task_id);
+        let result = TaskResult {
             task_id,
-            status: TaskStatus::Running,
-            error_info: None,
+            status: TaskStatus::Failed,
+            error_info: Some(format!("skipped: upstream {upstream_id} failed")),
+            attempts: 0,
         };
+        results.insert(task_id, result.clone());
+        skipped.push(result);
 
-        match TaskBlueprint::execute(task_id).await {
-            Ok(()) => result.status = TaskStatus::Completed,
-            Err(e) => {
-                result.status = TaskStatus::Failed;
-                result.error_info = Some(e.to_string());
+        if let Some(deps) = dependents.get(&task_id) {
+            for &dependent in deps {
+                Self::fail_transitively(
+                    dependent,
+                    task_id,
+                    dependents,
+                    results,
+                    remaining_in_degree,
+                    skipped,
+                );
             }
         }
+    }
+
+    /// Rejects the dependency graph up front if it contains a cycle, via a
+    /// Kahn's-algorithm pass: if some tasks never reach in-degree zero,
+    /// they're stuck in a cycle.
+    fn reject_cycles(
+        in_degree: &HashMap<u64, usize>,
+        dependents: &HashMap<u64, Vec<u64>>,
+    ) -> Result<()> {
+        let mut degree = in_degree.clone();
+        let mut queue: Vec<u64> = degree
+            .iter()
+            .filter(|(_, deg)| **deg == 0)
+            .map(|(id, _)| *id)
+            .collect();
+        let mut visited = 0usize;
+
+        while let Some(id) = queue.pop() {
+            visited += 1;
+            if let Some(deps) = dependents.get(&id) {
+                for &dependent in deps {
+                    if let Some(deg) = degree.get_mut(&dependent) {
+                        *deg -= 1;
+                        if *deg == 0 {
+                            queue.push(dependent);
+                        }
+                    }
+                }
+            }
+        }
+
+        if visited != in_degree.len() {
+            anyhow::bail!("task dependency graph contains a cycle");
+        }
+        Ok(())
+    }
+
+    /// Runs a task to completion, retrying transient failures with
+    /// exponential backoff and jitter up to `MAX_ATTEMPTS` times. 4xx
+    /// responses from `fetch_data` are treated as permanent and not retried.
+    async fn execute_single_task(task_id: u64) -> TaskResult {
+        let mut delay = INITIAL_BACKOFF;
+        let mut attempts = 0;
+
+        loop {
+            attempts += 1;
 
-        result
+            match TaskBlueprint::execute(task_id).await {
+                Ok(()) => {
+                    return TaskResult {
+                        task_id,
+                        status: TaskStatus::Completed,
+                        error_info: None,
+                        attempts,
+                    };
+                }
+                Err(e) => {
+                    if !e.is_transient() || attempts >= MAX_ATTEMPTS {
+                        return TaskResult {
+                            task_id,
+                            status: TaskStatus::Failed,
+                            error_info: Some(e.to_string()),
+                            attempts,
+                        };
+                    }
+
+                    let jitter = delay.mul_f64(rand::thread_rng().gen_range(0.0..0.5));
+                    sleep(delay + jitter).await;
+                    delay = (delay * 2).min(MAX_BACKOFF);
+                }
+            }
+        }
     }
 }
 
@@ -93,14 +519,17 @@ mod tests {
             TaskInput {
                 task_id: 101,
                 task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
             },
             TaskInput {
                 task_id: 102,
                 task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
             },
             TaskInput {
                 task_id: 103,
                 task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
             },
         ];
 
@@ -120,10 +549,12 @@ mod tests {
             TaskInput {
                 task_id: 101,
                 task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
             },
             TaskInput {
                 task_id: 101,
                 task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
             },
         ];
 
@@ -141,10 +572,12 @@ mod tests {
             TaskInput {
                 task_id: 201,
                 task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
             },
             TaskInput {
                 task_id: 202,
                 task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
             },
         ];
 
@@ -155,4 +588,275 @@ mod tests {
         assert!(task_ids.contains(&201));
         assert!(task_ids.contains(&202));
     }
+
+    #[tokio::test]
+    async fn test_orchestrator_bounded_concurrency() {
+        let orchestrator = TaskOrchestrator::new().with_concurrency(2);
+        let tasks = (301..=305)
+            .map(|task_id| TaskInput {
+                task_id,
+                task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
+            })
+            .collect();
+
+        let results = orchestrator.execute_tasks(tasks).await;
+        assert_eq!(results.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_orchestrator_streaming_bounded_concurrency() {
+        let orchestrator = TaskOrchestrator::new().with_concurrency(2);
+        let tasks = (401..=405)
+            .map(|task_id| TaskInput {
+                task_id,
+                task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
+            })
+            .collect();
+
+        let results = orchestrator.execute_tasks_streaming(tasks).await;
+        assert_eq!(results.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_execute_tasks_to_writer_streams_rows() {
+        use crate::task::MemorySink;
+
+        let orchestrator = TaskOrchestrator::new();
+        let tasks = vec![
+            TaskInput {
+                task_id: 601,
+                task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
+            },
+            TaskInput {
+                task_id: 602,
+                task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
+            },
+        ];
+
+        let sink = MemorySink::new();
+        orchestrator
+            .execute_tasks_to_writer(tasks, sink.clone())
+            .await
+            .unwrap();
+
+        let output = String::from_utf8(sink.take_buf()).unwrap();
+        assert!(output.contains("task_id,final_status,error_info,attempts"));
+        assert!(output.contains("601,"));
+        assert!(output.contains("602,"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_tasks_to_writer_dedupes_last_write_wins() {
+        use crate::task::MemorySink;
+
+        let orchestrator = TaskOrchestrator::new();
+        let tasks = vec![
+            TaskInput {
+                task_id: 701,
+                task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
+            },
+            TaskInput {
+                task_id: 701,
+                task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
+            },
+        ];
+
+        let sink = MemorySink::new();
+        orchestrator
+            .execute_tasks_to_writer(tasks, sink.clone())
+            .await
+            .unwrap();
+
+        let output = String::from_utf8(sink.take_buf()).unwrap();
+        let lines: Vec<&str> = output.lines().filter(|line| !line.is_empty()).collect();
+        assert_eq!(lines.len(), 2); // Header + 1 deduplicated row
+    }
+
+    #[tokio::test]
+    async fn test_dedupe_and_write_keeps_last_arrival_not_first() {
+        use crate::task::MemorySink;
+
+        let mut remaining: HashMap<u64, u32> = HashMap::default();
+        remaining.insert(701, 2);
+
+        let (tx, rx) = mpsc::channel::<TaskResult>(2);
+        tx.send(TaskResult {
+            task_id: 701,
+            status: TaskStatus::Failed,
+            error_info: Some("first attempt".to_string()),
+            attempts: 1,
+        })
+        .await
+        .unwrap();
+        tx.send(TaskResult {
+            task_id: 701,
+            status: TaskStatus::Completed,
+            error_info: None,
+            attempts: 2,
+        })
+        .await
+        .unwrap();
+        drop(tx);
+
+        let sink = MemorySink::new();
+        let mut writer = sink.clone();
+        TaskOrchestrator::dedupe_and_write(rx, remaining, &mut writer)
+            .await
+            .unwrap();
+
+        let output = String::from_utf8(sink.take_buf()).unwrap();
+        assert!(output.contains("701,Completed,,2"));
+        assert!(!output.contains("Failed"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_tasks_records_through_sink() {
+        let sink = Arc::new(CsvResultSink::new());
+        let orchestrator = TaskOrchestrator::new().with_sink(sink.clone());
+        let tasks = vec![TaskInput {
+            task_id: 901,
+            task_type: "process_data".to_string(),
+            depends_on: Vec::new(),
+        }];
+
+        orchestrator.execute_tasks(tasks).await;
+
+        let sink = Arc::try_unwrap(sink).expect("sink still shared");
+        let csv = sink.into_csv().await.unwrap();
+        assert!(csv.contains("901,"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_tasks_with_registry_tracks_and_completes() {
+        let orchestrator = TaskOrchestrator::new();
+        let tasks = vec![
+            TaskInput {
+                task_id: 1101,
+                task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
+            },
+            TaskInput {
+                task_id: 1102,
+                task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
+            },
+        ];
+
+        let (registry, handle) = orchestrator.execute_tasks_with_registry(tasks);
+        let results = handle.await.unwrap();
+        assert_eq!(results.len(), 2);
+
+        let snapshot = registry.snapshot().await;
+        assert_eq!(snapshot.len(), 2);
+        assert!(snapshot.values().all(|s| s.status == TaskStatus::Completed));
+    }
+
+    #[tokio::test]
+    async fn test_execute_tasks_with_dependencies_runs_all_tasks() {
+        let orchestrator = TaskOrchestrator::new();
+        let tasks = vec![
+            TaskInput {
+                task_id: 1201,
+                task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
+            },
+            TaskInput {
+                task_id: 1202,
+                task_type: "process_data".to_string(),
+                depends_on: vec![1201],
+            },
+        ];
+
+        let results = orchestrator
+            .execute_tasks_with_dependencies(tasks)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.status == TaskStatus::Completed));
+    }
+
+    #[tokio::test]
+    async fn test_execute_tasks_with_dependencies_rejects_cycle() {
+        let orchestrator = TaskOrchestrator::new();
+        let tasks = vec![
+            TaskInput {
+                task_id: 1301,
+                task_type: "process_data".to_string(),
+                depends_on: vec![1302],
+            },
+            TaskInput {
+                task_id: 1302,
+                task_type: "process_data".to_string(),
+                depends_on: vec![1301],
+            },
+        ];
+
+        let err = orchestrator
+            .execute_tasks_with_dependencies(tasks)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("cycle"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_tasks_with_dependencies_handles_duplicate_ids() {
+        let orchestrator = TaskOrchestrator::new();
+        let tasks = vec![
+            TaskInput {
+                task_id: 1401,
+                task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
+            },
+            TaskInput {
+                task_id: 1401,
+                task_type: "process_data".to_string(),
+                depends_on: Vec::new(),
+            },
+        ];
+
+        let results = orchestrator
+            .execute_tasks_with_dependencies(tasks)
+            .await
+            .unwrap();
+
+        assert!(results.iter().all(|r| r.status == TaskStatus::Completed));
+    }
+
+    #[tokio::test]
+    async fn test_fail_transitively_collects_skipped_results_for_sink_recording() {
+        let mut dependents: HashMap<u64, Vec<u64>> = HashMap::default();
+        dependents.insert(1501, vec![1502]);
+        dependents.insert(1502, vec![1503]);
+
+        let mut results: HashMap<u64, TaskResult> = HashMap::default();
+        let mut remaining_in_degree: HashMap<u64, usize> = HashMap::default();
+        remaining_in_degree.insert(1502, 1);
+        remaining_in_degree.insert(1503, 1);
+
+        let mut skipped = Vec::new();
+        TaskOrchestrator::fail_transitively(
+            1502,
+            1501,
+            &dependents,
+            &mut results,
+            &mut remaining_in_degree,
+            &mut skipped,
+        );
+
+        // Every transitively-skipped result must show up in `skipped` too,
+        // not just `results` — that's what lets the caller record it
+        // through the sink the same as an executed task's result.
+        assert_eq!(skipped.len(), 2);
+        assert!(skipped.iter().all(|r| r.status == TaskStatus::Failed));
+        assert!(skipped.iter().any(|r| r.task_id == 1502
+            && r.error_info.as_deref() == Some("skipped: upstream 1501 failed")));
+        assert!(skipped.iter().any(|r| r.task_id == 1503));
+    }
 }
@@ -0,0 +1,186 @@
+use crate::task::TaskStatus;
+use ahash::AHashMap as HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+struct RegistryEntry {
+    /// `None` for the brief window between [`TaskRegistry::insert_pending`]
+    /// and [`TaskRegistry::attach_handle`], while the task has been
+    /// registered but `tokio::spawn` hasn't returned its handle yet.
+    handle: Option<JoinHandle<()>>,
+    status: TaskStatus,
+    error_info: Option<String>,
+}
+
+/// A task's last known status as seen by a [`TaskRegistry`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskStatusSnapshot {
+    pub status: TaskStatus,
+    pub error_info: Option<String>,
+}
+
+/// Tracks in-flight task handles and their current status for a single
+/// orchestrator run, so progress can be observed and individual tasks
+/// cancelled while the run is still going on — impossible with the plain
+/// fire-and-forget spawning `execute_tasks` used to do.
+#[derive(Clone, Default)]
+pub struct TaskRegistry {
+    entries: Arc<Mutex<HashMap<u64, RegistryEntry>>>,
+}
+
+impl TaskRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `task_id` as `Running` with no handle yet. Call this
+    /// *before* `tokio::spawn`-ing the task's future, then [`Self::attach_handle`]
+    /// once spawning returns its handle — otherwise the spawned task can
+    /// reach [`Self::set_status`] before the entry exists, and that status
+    /// update is silently dropped.
+    pub(crate) async fn insert_pending(&self, task_id: u64) {
+        self.entries.lock().await.insert(
+            task_id,
+            RegistryEntry {
+                handle: None,
+                status: TaskStatus::Running,
+                error_info: None,
+            },
+        );
+    }
+
+    /// Attaches the handle for a task previously registered with
+    /// [`Self::insert_pending`].
+    pub(crate) async fn attach_handle(&self, task_id: u64, handle: JoinHandle<()>) {
+        if let Some(entry) = self.entries.lock().await.get_mut(&task_id) {
+            entry.handle = Some(handle);
+        }
+    }
+
+    pub(crate) async fn set_status(
+        &self,
+        task_id: u64,
+        status: TaskStatus,
+        error_info: Option<String>,
+    ) {
+        if let Some(entry) = self.entries.lock().await.get_mut(&task_id) {
+            entry.status = status;
+            entry.error_info = error_info;
+        }
+    }
+
+    /// Snapshot of every tracked task's current status.
+    pub async fn snapshot(&self) -> HashMap<u64, TaskStatusSnapshot> {
+        self.entries
+            .lock()
+            .await
+            .iter()
+            .map(|(id, entry)| {
+                (
+                    *id,
+                    TaskStatusSnapshot {
+                        status: entry.status.clone(),
+                        error_info: entry.error_info.clone(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Looks up a single task's current status.
+    pub async fn get(&self, task_id: u64) -> Option<TaskStatusSnapshot> {
+        self.entries
+            .lock()
+            .await
+            .get(&task_id)
+            .map(|entry| TaskStatusSnapshot {
+                status: entry.status.clone(),
+                error_info: entry.error_info.clone(),
+            })
+    }
+
+    /// Aborts the task's handle and marks it `Failed` with an `error_info`
+    /// of "cancelled". Returns `false` if the task isn't running (already
+    /// finished or cancelled, or an unknown id), and leaves the task running if its
+    /// handle hasn't been attached yet (the brief window right after
+    /// [`Self::insert_pending`] but before [`Self::attach_handle`]).
+    pub async fn cancel(&self, task_id: u64) -> bool {
+        let mut entries = self.entries.lock().await;
+        let Some(entry) = entries.get_mut(&task_id) else {
+            return false;
+        };
+        if entry.status != TaskStatus::Running {
+            return false;
+        }
+        let Some(handle) = &entry.handle else {
+            return false;
+        };
+
+        handle.abort();
+        entry.status = TaskStatus::Failed;
+        entry.error_info = Some("cancelled".to_string());
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_snapshot_and_get_reflect_status_updates() {
+        let registry = TaskRegistry::new();
+        registry.insert_pending(1001).await;
+        let handle = tokio::spawn(async {});
+        registry.attach_handle(1001, handle).await;
+
+        assert_eq!(
+            registry.get(1001).await.unwrap().status,
+            TaskStatus::Running
+        );
+
+        registry.set_status(1001, TaskStatus::Completed, None).await;
+
+        let snapshot = registry.snapshot().await;
+        assert_eq!(snapshot.get(&1001).unwrap().status, TaskStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_marks_failed_and_aborts() {
+        let registry = TaskRegistry::new();
+        registry.insert_pending(1002).await;
+        let handle = tokio::spawn(async {
+            tokio::time::sleep(tokio::time::Duration::from_secs(60)).await;
+        });
+        registry.attach_handle(1002, handle).await;
+
+        assert!(registry.cancel(1002).await);
+
+        let status = registry.get(1002).await.unwrap();
+        assert_eq!(status.status, TaskStatus::Failed);
+        assert_eq!(status.error_info.as_deref(), Some("cancelled"));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_unknown_task_returns_false() {
+        let registry = TaskRegistry::new();
+        assert!(!registry.cancel(9999).await);
+    }
+
+    #[tokio::test]
+    async fn test_set_status_before_attach_handle_is_not_lost() {
+        let registry = TaskRegistry::new();
+        registry.insert_pending(1003).await;
+
+        // Simulates the spawned task finishing before the caller gets
+        // around to attaching its handle.
+        registry
+            .set_status(1003, TaskStatus::Completed, None)
+            .await;
+        registry.attach_handle(1003, tokio::spawn(async {})).await;
+
+        let status = registry.get(1003).await.unwrap();
+        assert_eq!(status.status, TaskStatus::Completed);
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{orchestrator::TaskOrchestrator, task::TaskInput};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering as AtomicOrdering};
+use std::sync::Arc;
+use tokio::sync::{Mutex, Notify};
+use tokio::time::{sleep_until, Duration, Instant};
+
+/// A single recurring job: a task to run every `interval`, optionally capped
+/// at a maximum number of runs.
+#[derive(Debug, Clone)]
+struct ScheduleEntry {
+    id: u64,
+    task: TaskInput,
+    interval: Duration,
+    next_run: Instant,
+    max_runs: Option<u32>,
+    runs_so_far: u32,
+}
+
+impl ScheduleEntry {
+    fn is_exhausted(&self) -> bool {
+        matches!(self.max_runs, Some(max) if self.runs_so_far >= max)
+    }
+}
+
+// `BinaryHeap` is a max-heap; reverse the comparison on `next_run` so the
+// heap behaves as a min-heap ordered by earliest-due-first.
+impl PartialEq for ScheduleEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.next_run == other.next_run
+    }
+}
+
+impl Eq for ScheduleEntry {}
+
+impl PartialOrd for ScheduleEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScheduleEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.next_run.cmp(&self.next_run)
+    }
+}
+
+/// Runs tasks repeatedly on a schedule instead of once per CSV invocation.
+///
+/// Entries live in a min-heap keyed by `next_run`. The dispatch loop sleeps
+/// until the earliest one is due, spawns it on a `TaskOrchestrator` without
+/// waiting for it to finish, then reschedules it. Running each job in the
+/// background keeps one slow run from blocking dispatch of every other due
+/// entry. If the loop fell behind, missed slots are skipped rather than
+/// replayed so it never busy-loops catching up.
+pub struct Scheduler {
+    entries: Arc<Mutex<BinaryHeap<ScheduleEntry>>>,
+    next_id: Arc<AtomicU64>,
+    added: Arc<Notify>,
+    stopped: Arc<Notify>,
+    running: Arc<AtomicBool>,
+    handle: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(BinaryHeap::new())),
+            next_id: Arc::new(AtomicU64::new(1)),
+            added: Arc::new(Notify::new()),
+            stopped: Arc::new(Notify::new()),
+            running: Arc::new(AtomicBool::new(false)),
+            handle: None,
+        }
+    }
+
+    /// Schedules `task` to run every `interval`, optionally stopping after
+    /// `max_runs` executions. Returns an id usable with [`Scheduler::remove`].
+    pub async fn add(&self, task: TaskInput, interval: Duration, max_runs: Option<u32>) -> u64 {
+        let id = self.next_id.fetch_add(1, AtomicOrdering::SeqCst);
+        let entry = ScheduleEntry {
+            id,
+            task,
+            interval,
+            next_run: Instant::now() + interval,
+            max_runs,
+            runs_so_far: 0,
+        };
+
+        self.entries.lock().await.push(entry);
+        self.added.notify_one();
+        id
+    }
+
+    /// Removes a previously added entry, if it still exists.
+    pub async fn remove(&self, id: u64) {
+        let mut entries = self.entries.lock().await;
+        let remaining: BinaryHeap<ScheduleEntry> =
+            entries.drain().filter(|entry| entry.id != id).collect();
+        *entries = remaining;
+    }
+
+    /// Starts the dispatch loop as a background task. No-op if already running.
+    pub fn start(&mut self) {
+        if self.running.swap(true, AtomicOrdering::SeqCst) {
+            return;
+        }
+
+        let entries = self.entries.clone();
+        let added = self.added.clone();
+        let stopped = self.stopped.clone();
+        let running = self.running.clone();
+
+        self.handle = Some(tokio::spawn(async move {
+            while running.load(AtomicOrdering::SeqCst) {
+                let next_run = entries.lock().await.peek().map(|entry| entry.next_run);
+
+                match next_run {
+                    Some(next_run) => {
+                        tokio::select! {
+                            _ = sleep_until(next_run) => {}
+                            _ = added.notified() => continue,
+                            _ = stopped.notified() => break,
+                        }
+                    }
+                    None => {
+                        tokio::select! {
+                            _ = added.notified() => continue,
+                            _ = stopped.notified() => break,
+                        }
+                    }
+                }
+
+                let due = {
+                    let mut entries = entries.lock().await;
+                    match entries.peek() {
+                        Some(entry) if entry.next_run <= Instant::now() => entries.pop(),
+                        _ => None,
+                    }
+                };
+
+                let Some(mut entry) = due else { continue };
+
+                // Run in the background so one slow job can't block dispatch
+                // of every other due entry, or delay reacting to `add`/`stop`.
+                let task = entry.task.clone();
+                tokio::spawn(async move {
+                    TaskOrchestrator::new().execute_tasks(vec![task]).await;
+                });
+
+                entry.runs_so_far += 1;
+                if !entry.is_exhausted() {
+                    let now = Instant::now();
+                    // Skip missed slots instead of replaying a backlog.
+                    while entry.next_run <= now {
+                        entry.next_run += entry.interval;
+                    }
+                    entries.lock().await.push(entry);
+                }
+            }
+        }));
+    }
+
+    /// Stops the dispatch loop. Safe to call even if not running.
+    pub fn stop(&mut self) {
+        if !self.running.swap(false, AtomicOrdering::SeqCst) {
+            return;
+        }
+        self.stopped.notify_one();
+        self.handle = None;
+    }
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(task_id: u64) -> TaskInput {
+        TaskInput {
+            task_id,
+            task_type: "process_data".to_string(),
+            depends_on: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_and_remove_entry() {
+        let scheduler = Scheduler::new();
+        let id = scheduler
+            .add(task(501), Duration::from_secs(60), None)
+            .await;
+
+        assert_eq!(scheduler.entries.lock().await.len(), 1);
+
+        scheduler.remove(id).await;
+        assert_eq!(scheduler.entries.lock().await.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_start_stop_is_idempotent() {
+        let mut scheduler = Scheduler::new();
+        scheduler.start();
+        scheduler.start(); // no-op, already running
+        scheduler.stop();
+        scheduler.stop(); // no-op, already stopped
+    }
+}
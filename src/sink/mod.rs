@@ -0,0 +1,94 @@
+use crate::task::{write_results_to_csv, TaskResult};
+use anyhow::Result;
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "postgres")]
+pub use postgres::PostgresResultSink;
+
+/// Pluggable backend for persisting task results as they complete.
+///
+/// `TaskOrchestrator` writes every result through a `ResultSink` rather than
+/// being hardcoded to CSV, so result storage (in memory, in Postgres, ...)
+/// can be swapped independently of task execution.
+#[async_trait]
+pub trait ResultSink: Send + Sync {
+    async fn record(&self, result: &TaskResult) -> Result<()>;
+}
+
+/// Default sink: buffers recorded results in memory and renders them to CSV
+/// on demand via [`write_results_to_csv`], keeping only the latest result
+/// per `task_id` (last-write-wins), matching the orchestrator's original
+/// behavior.
+#[derive(Debug, Default)]
+pub struct CsvResultSink {
+    results: Mutex<Vec<TaskResult>>,
+}
+
+impl CsvResultSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renders all recorded results to CSV.
+    pub async fn into_csv(self) -> Result<String> {
+        write_results_to_csv(&self.results.into_inner())
+    }
+}
+
+#[async_trait]
+impl ResultSink for CsvResultSink {
+    async fn record(&self, result: &TaskResult) -> Result<()> {
+        self.results.lock().await.push(result.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::TaskStatus;
+
+    #[tokio::test]
+    async fn test_csv_sink_records_and_renders() {
+        let sink = CsvResultSink::new();
+        sink.record(&TaskResult {
+            task_id: 801,
+            status: TaskStatus::Completed,
+            error_info: None,
+            attempts: 1,
+        })
+        .await
+        .unwrap();
+
+        let csv = sink.into_csv().await.unwrap();
+        assert!(csv.contains("801,Completed,,1"));
+    }
+
+    #[tokio::test]
+    async fn test_csv_sink_last_write_wins() {
+        let sink = CsvResultSink::new();
+        sink.record(&TaskResult {
+            task_id: 802,
+            status: TaskStatus::Failed,
+            error_info: Some("timeout".to_string()),
+            attempts: 1,
+        })
+        .await
+        .unwrap();
+        sink.record(&TaskResult {
+            task_id: 802,
+            status: TaskStatus::Completed,
+            error_info: None,
+            attempts: 2,
+        })
+        .await
+        .unwrap();
+
+        let csv = sink.into_csv().await.unwrap();
+        assert!(csv.contains("802,Completed,,2"));
+        assert!(!csv.contains("timeout"));
+    }
+}
@@ -0,0 +1,66 @@
+use super::ResultSink;
+use crate::task::{TaskResult, TaskStatus};
+use anyhow::Result;
+use async_trait::async_trait;
+use deadpool_postgres::{Config, Pool, Runtime};
+use tokio_postgres::NoTls;
+
+/// Postgres-backed result sink so multiple orchestrator processes can share
+/// result state and survive restarts. Behind the `postgres` feature since it
+/// pulls in a connection pool and a Postgres client.
+pub struct PostgresResultSink {
+    pool: Pool,
+}
+
+impl PostgresResultSink {
+    /// Connects using `config` and ensures the `task_results` table exists.
+    pub async fn connect(config: Config) -> Result<Self> {
+        let pool = config.create_pool(Some(Runtime::Tokio1), NoTls)?;
+        let sink = Self { pool };
+        sink.ensure_schema().await?;
+        Ok(sink)
+    }
+
+    async fn ensure_schema(&self) -> Result<()> {
+        let client = self.pool.get().await?;
+        client
+            .batch_execute(
+                "CREATE TABLE IF NOT EXISTS task_results (
+                    task_id BIGINT PRIMARY KEY,
+                    final_status TEXT NOT NULL,
+                    error_info TEXT,
+                    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+                )",
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ResultSink for PostgresResultSink {
+    /// Upserts on `task_id` so the latest status wins, matching the CSV
+    /// sink's last-write-wins semantics but durably and concurrently.
+    async fn record(&self, result: &TaskResult) -> Result<()> {
+        let final_status = match result.status {
+            TaskStatus::Completed => "Completed",
+            TaskStatus::Failed => "Failed",
+            TaskStatus::Pending => "Pending",
+            TaskStatus::Running => "Running",
+        };
+
+        let client = self.pool.get().await?;
+        client
+            .execute(
+                "INSERT INTO task_results (task_id, final_status, error_info, updated_at)
+                 VALUES ($1, $2, $3, now())
+                 ON CONFLICT (task_id) DO UPDATE
+                 SET final_status = EXCLUDED.final_status,
+                     error_info = EXCLUDED.error_info,
+                     updated_at = EXCLUDED.updated_at",
+                &[&(result.task_id as i64), &final_status, &result.error_info],
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -2,11 +2,33 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 //use std::collections::HashMap;
 use ahash::AHashMap as HashMap;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tokio::io::AsyncWrite;
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct TaskInput {
     pub task_id: u64,
     pub task_type: String,
+    /// Ids of tasks that must complete before this one runs. Parsed from an
+    /// optional CSV column holding a `;`-separated list, e.g. `"1;2"`.
+    #[serde(default, deserialize_with = "deserialize_depends_on")]
+    pub depends_on: Vec<u64>,
+}
+
+fn deserialize_depends_on<'de, D>(deserializer: D) -> std::result::Result<Vec<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let raw = String::deserialize(deserializer)?;
+    if raw.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    raw.split(';')
+        .map(|id| id.trim().parse::<u64>().map_err(serde::de::Error::custom))
+        .collect()
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -22,6 +44,8 @@ pub struct TaskResult {
     pub task_id: u64,
     pub status: TaskStatus,
     pub error_info: Option<String>,
+    /// Number of attempts made, including the final (successful or failed) one.
+    pub attempts: u32,
 }
 
 #[derive(Debug, Serialize)]
@@ -29,6 +53,7 @@ pub struct TaskOutput {
     pub task_id: u64,
     pub final_status: String,
     pub error_info: String,
+    pub attempts: u32,
 }
 
 impl From<TaskResult> for TaskOutput {
@@ -45,6 +70,7 @@ impl From<TaskResult> for TaskOutput {
             task_id: result.task_id,
             final_status,
             error_info,
+            attempts: result.attempts,
         }
     }
 }
@@ -81,6 +107,45 @@ pub fn write_results_to_csv(results: &[TaskResult]) -> Result<String> {
     Ok(String::from_utf8(data)?)
 }
 
+/// In-memory `AsyncWrite` sink backed by a shared buffer. Handed to
+/// [`crate::orchestrator::TaskOrchestrator::execute_tasks_to_writer`] by
+/// tests and by callers that still want the serialized CSV as a `String`
+/// rather than writing to a file or socket.
+#[derive(Debug, Clone, Default)]
+pub struct MemorySink {
+    buf: Arc<Mutex<Vec<u8>>>,
+}
+
+impl MemorySink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the bytes written so far, leaving the sink empty.
+    pub fn take_buf(&self) -> Vec<u8> {
+        std::mem::take(&mut self.buf.lock().unwrap())
+    }
+}
+
+impl AsyncWrite for MemorySink {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.buf.lock().unwrap().extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,18 +172,20 @@ mod tests {
                 task_id: 101,
                 status: TaskStatus::Completed,
                 error_info: None,
+                attempts: 1,
             },
             TaskResult {
                 task_id: 102,
                 status: TaskStatus::Failed,
                 error_info: Some("Network error".to_string()),
+                attempts: 3,
             },
         ];
 
         let output = write_results_to_csv(&results).unwrap();
-        assert!(output.contains("task_id,final_status,error_info"));
-        assert!(output.contains("101,Completed,"));
-        assert!(output.contains("102,Failed,Network error"));
+        assert!(output.contains("task_id,final_status,error_info,attempts"));
+        assert!(output.contains("101,Completed,,1"));
+        assert!(output.contains("102,Failed,Network error,3"));
     }
 
     #[test]
@@ -128,17 +195,19 @@ mod tests {
                 task_id: 101,
                 status: TaskStatus::Running,
                 error_info: None,
+                attempts: 1,
             },
             TaskResult {
                 task_id: 101,
                 status: TaskStatus::Completed,
                 error_info: None,
+                attempts: 2,
             },
         ];
 
         let output = write_results_to_csv(&results).unwrap();
         let lines: Vec<&str> = output.lines().filter(|line| !line.is_empty()).collect();
         assert_eq!(lines.len(), 2); // Header + 1 unique result
-        assert!(output.contains("101,Completed,"));
+        assert!(output.contains("101,Completed,,2"));
     }
 }
@@ -1,17 +1,38 @@
-use anyhow::Result;
+use thiserror::Error;
 use tokio::time::{sleep, Duration};
 
+/// Error from a single blueprint attempt, classified so the orchestrator
+/// knows whether it is worth retrying.
+#[derive(Debug, Error)]
+pub enum FetchError {
+    #[error("network error: {0}")]
+    Network(#[from] reqwest::Error),
+    #[error("HTTP request failed with status: {0}")]
+    Status(reqwest::StatusCode),
+}
+
+impl FetchError {
+    /// Network failures and 5xx responses are transient and worth retrying;
+    /// 4xx responses are permanent client errors.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            FetchError::Network(_) => true,
+            FetchError::Status(status) => status.is_server_error(),
+        }
+    }
+}
+
 pub struct TaskBlueprint;
 
 impl TaskBlueprint {
-    pub async fn execute(task_id: u64) -> Result<()> {
+    pub async fn execute(task_id: u64) -> Result<(), FetchError> {
         Self::fetch_data("https://httpbin.org/get").await?;
         Self::long_delay().await;
         Self::emit_event(task_id).await;
         Ok(())
     }
 
-    async fn fetch_data(url: &str) -> Result<()> {
+    async fn fetch_data(url: &str) -> Result<(), FetchError> {
         let client = reqwest::Client::builder()
             .timeout(std::time::Duration::from_secs(10))
             .build()?;
@@ -19,10 +40,7 @@ impl TaskBlueprint {
         let response = client.get(url).send().await?;
 
         if !response.status().is_success() {
-            return Err(anyhow::anyhow!(
-                "HTTP request failed with status: {}",
-                response.status()
-            ));
+            return Err(FetchError::Status(response.status()));
         }
 
         // Consume the response body to ensure the request is complete